@@ -1,4 +1,4 @@
-use std::ops::Index;
+use std::ops::{Index, IndexMut};
 
 /// Matrix represents a 2D matrix in a flat vector where the indexing is row major
 pub struct Matrix<T: Clone> {
@@ -55,6 +55,107 @@ impl<T: Clone> Matrix<T> {
         *x = new_val;
         Ok(())
     }
+
+    /// Retrieves mutable references to `N` disjoint cells at once, given their coordinates.
+    /// Each coordinate is flattened to the row-major index `cols * row + col`, as in `get`.
+    /// Returns `None` if any flattened index is past the end of the matrix or if two coordinates
+    /// flatten to the same cell. This is useful for in-place operations that need to hold more than one mutable
+    /// reference at a time, such as swapping two entries, where `get_mut` alone cannot satisfy
+    /// the borrow checker.
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        coords: [(usize, usize); N],
+    ) -> Option<[&mut T; N]> {
+        let flat = coords.map(|(r, c)| self.cols * r + c);
+
+        if flat.iter().any(|&idx| idx >= self.data.len()) {
+            return None;
+        }
+        for i in 0..N {
+            for j in (i + 1)..N {
+                if flat[i] == flat[j] {
+                    return None;
+                }
+            }
+        }
+
+        Some(unsafe { self.get_many_unchecked_mut(coords) })
+    }
+
+    /// Returns the contiguous slice of elements making up row `r`, or `None` if `r` is out of
+    /// bounds.
+    pub fn row(&self, r: usize) -> Option<&[T]> {
+        if r >= self.rows {
+            return None;
+        }
+        Some(&self.data[r * self.cols..(r + 1) * self.cols])
+    }
+
+    /// Returns an iterator over the matrix's rows, each yielded as a contiguous slice. A matrix
+    /// with zero columns yields no rows, whatever its row count.
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, but a 0-column matrix always has empty `data`, so
+        // any positive chunk size yields the same (empty) result.
+        self.data.chunks(self.cols.max(1))
+    }
+
+    /// Returns an iterator over the elements of column `c`, stepping through the flat backing
+    /// store with a stride of `cols`, or `None` if `c` is out of bounds.
+    pub fn col_iter(&self, c: usize) -> Option<impl Iterator<Item = &T>> {
+        if c >= self.cols {
+            return None;
+        }
+        Some(self.data.iter().skip(c).step_by(self.cols))
+    }
+
+    /// Swaps two entire rows in place without allocating. This is a no-op when `a == b`.
+    pub fn swap_rows(&mut self, a: usize, b: usize) -> Result<(), &str> {
+        if a >= self.rows || b >= self.rows {
+            return Err("Invalid Index");
+        }
+        if a == b {
+            return Ok(());
+        }
+
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        let (head, tail) = self.data.split_at_mut(hi * self.cols);
+        let lo_row = &mut head[lo * self.cols..(lo + 1) * self.cols];
+        let hi_row = &mut tail[..self.cols];
+        lo_row.swap_with_slice(hi_row);
+
+        Ok(())
+    }
+
+    /// Swaps two entire columns in place without allocating. This is a no-op when `a == b`.
+    pub fn swap_cols(&mut self, a: usize, b: usize) -> Result<(), &str> {
+        if a >= self.cols || b >= self.cols {
+            return Err("Invalid Index");
+        }
+        if a == b {
+            return Ok(());
+        }
+
+        for r in 0..self.rows {
+            self.data.swap(r * self.cols + a, r * self.cols + b);
+        }
+
+        Ok(())
+    }
+
+    /// Similar to `get_many_mut`, but without the bounds and overlap checks.
+    ///
+    /// # Safety
+    ///
+    /// Every coordinate must be in bounds and all coordinates must be pairwise distinct.
+    /// Violating either invariant is undefined behavior, since it would produce two mutable
+    /// references that alias the same element.
+    pub unsafe fn get_many_unchecked_mut<const N: usize>(
+        &mut self,
+        coords: [(usize, usize); N],
+    ) -> [&mut T; N] {
+        let base = self.data.as_mut_ptr();
+        coords.map(|(r, c)| &mut *base.add(self.cols * r + c))
+    }
 }
 
 impl<T: Clone + Default> Matrix<T> {
@@ -78,6 +179,14 @@ impl<T: Clone> Index<(usize, usize)> for Matrix<T> {
     }
 }
 
+/// Implement the mutable index trait for Matrix, allowing assignment through `m[(i, j)] = v`.
+/// The first element in the tuple is the row index and the second element is the column index.
+impl<T: Clone> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, coord: (usize, usize)) -> &mut Self::Output {
+        &mut self.data[(self.cols * coord.0) + coord.1]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,6 +227,21 @@ mod tests {
         mat[(100, 0)];
     }
 
+    #[test]
+    fn test_index_mut() {
+        let mut mat = Matrix::<i32>::new(10, 10);
+        mat[(0, 0)] = 5;
+
+        assert_eq!(mat[(0, 0)], 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_mut_out_of_bounds() {
+        let mut mat = Matrix::<f32>::new(100, 100);
+        mat[(100, 0)] = 1.0;
+    }
+
     #[test]
     fn test_get() {
         let mat = Matrix::<i32>::new(10, 10);
@@ -143,4 +267,103 @@ mod tests {
         assert_eq!(mat.get((0, 0)), Some(&10));
         assert_eq!(mat.set((100, 100), 20), Err("Invalid Index"));
     }
+
+    #[test]
+    fn test_get_many_mut() {
+        let mut mat = Matrix::<i32>::new(3, 3);
+        let [a, b] = mat.get_many_mut([(0, 0), (1, 2)]).unwrap();
+        *a = 1;
+        *b = 2;
+
+        assert_eq!(mat.get((0, 0)), Some(&1));
+        assert_eq!(mat.get((1, 2)), Some(&2));
+    }
+
+    #[test]
+    fn test_get_many_mut_overlap() {
+        let mut mat = Matrix::<i32>::new(3, 3);
+        assert!(mat.get_many_mut([(0, 0), (0, 0)]).is_none());
+    }
+
+    #[test]
+    fn test_get_many_mut_out_of_bounds() {
+        let mut mat = Matrix::<i32>::new(3, 3);
+        assert!(mat.get_many_mut([(0, 0), (100, 100)]).is_none());
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut mat = Matrix::<i32>::new(2, 3);
+        mat.set((0, 0), 1).unwrap();
+        mat.set((1, 0), 2).unwrap();
+
+        assert_eq!(mat.swap_rows(0, 1), Ok(()));
+        assert_eq!(mat.get((0, 0)), Some(&2));
+        assert_eq!(mat.get((1, 0)), Some(&1));
+        assert_eq!(mat.swap_rows(0, 100), Err("Invalid Index"));
+    }
+
+    #[test]
+    fn test_row() {
+        let mut mat = Matrix::<i32>::new(2, 3);
+        mat.set((1, 0), 1).unwrap();
+        mat.set((1, 1), 2).unwrap();
+        mat.set((1, 2), 3).unwrap();
+
+        assert_eq!(mat.row(1), Some([1, 2, 3].as_slice()));
+        assert_eq!(mat.row(100), None);
+    }
+
+    #[test]
+    fn test_rows_iter() {
+        let mut mat = Matrix::<i32>::new(2, 2);
+        mat.set((0, 0), 1).unwrap();
+        mat.set((0, 1), 2).unwrap();
+        mat.set((1, 0), 3).unwrap();
+        mat.set((1, 1), 4).unwrap();
+
+        let rows: Vec<&[i32]> = mat.rows_iter().collect();
+        assert_eq!(rows, vec![[1, 2].as_slice(), [3, 4].as_slice()]);
+    }
+
+    #[test]
+    fn test_rows_iter_zero_cols() {
+        let mat = Matrix::<i32>::new(100, 0);
+        assert_eq!(mat.rows_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_col_iter() {
+        let mut mat = Matrix::<i32>::new(2, 2);
+        mat.set((0, 1), 1).unwrap();
+        mat.set((1, 1), 2).unwrap();
+
+        let col: Vec<&i32> = mat.col_iter(1).unwrap().collect();
+        assert_eq!(col, vec![&1, &2]);
+    }
+
+    #[test]
+    fn test_col_iter_out_of_bounds() {
+        let mat = Matrix::<i32>::new(2, 3);
+        assert!(mat.col_iter(3).is_none());
+        assert!(mat.col_iter(100).is_none());
+    }
+
+    #[test]
+    fn test_col_iter_zero_rows() {
+        let mat = Matrix::<i32>::new(0, 5);
+        assert_eq!(mat.col_iter(3).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_swap_cols() {
+        let mut mat = Matrix::<i32>::new(3, 2);
+        mat.set((0, 0), 1).unwrap();
+        mat.set((0, 1), 2).unwrap();
+
+        assert_eq!(mat.swap_cols(0, 1), Ok(()));
+        assert_eq!(mat.get((0, 0)), Some(&2));
+        assert_eq!(mat.get((0, 1)), Some(&1));
+        assert_eq!(mat.swap_cols(0, 100), Err("Invalid Index"));
+    }
 }